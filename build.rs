@@ -19,15 +19,28 @@ fn main() {
     let schema_json = read_to_string(manifest_path.join("vk-schema.json"))
         .expect("failed to read vk-schema.json");
     let schema: RootSchema = from_str(&schema_json).expect("failed to parse vk-schema.json");
-    let mut type_space = TypeSpace::new(&TypeSpaceSettings::default());
+    let mut settings = TypeSpaceSettings::default();
+    if var("CARGO_FEATURE_SCHEMA_BUILDER").is_ok() {
+        settings.with_struct_builder(true);
+    }
+    if var("CARGO_FEATURE_SCHEMA_EXTRA_DERIVES").is_ok() {
+        settings
+            .with_derive("PartialEq".to_string())
+            .with_derive("Eq".to_string())
+            .with_derive("Hash".to_string())
+            .with_derive("Default".to_string());
+    }
+    let mut type_space = TypeSpace::new(&settings);
     type_space
         .add_root_schema(schema)
         .expect("failed to process schema");
     let contents = type_space.to_stream().to_string();
     fs::write(manifest_path.join("src/schema.rs"), contents).expect("failed to write schema.rs");
 
-    // Copy vk.json to OUT_DIR.
-    let vk_json_src = manifest_path.join("vk.json");
-    let vk_json_dst = Path::new(&out_dir).join("vk.json");
-    fs::copy(&vk_json_src, &vk_json_dst).expect("failed to copy vk.json to OUT_DIR");
+    // Copy vk.json to OUT_DIR, only when the embedded registry is wanted.
+    if var("CARGO_FEATURE_EMBED_REGISTRY").is_ok() {
+        let vk_json_src = manifest_path.join("vk.json");
+        let vk_json_dst = Path::new(&out_dir).join("vk.json");
+        fs::copy(&vk_json_src, &vk_json_dst).expect("failed to copy vk.json to OUT_DIR");
+    }
 }
@@ -0,0 +1,250 @@
+// Copyright 2023-2026 The Khronos Group Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! A Rust FFI binding-generation backend driven by a parsed [`VulkanObject`],
+//! in the spirit of vkgen/ash but reading from this crate's model instead of
+//! vk.xml directly.
+
+use std::fmt::Write as _;
+
+use crate::vulkan_object::VulkanObject;
+
+/// Options controlling what [`emit_rust`] produces.
+#[derive(Debug, Clone, Default)]
+pub struct EmitOptions {
+    /// Only emit handles, enums, bitmasks, structs and commands whose
+    /// `extensions` list names one of these extensions; constants are always
+    /// emitted. An empty list means "emit everything".
+    pub only: Vec<String>,
+}
+
+/// The platform guard (`VK_USE_PLATFORM_*`) for an extension is layered in
+/// separately by [`crate::resolve::ResolvedSet`]; this only gates on the
+/// feature/extension name itself.
+fn cfg_guard(extensions: &[String]) -> Option<String> {
+    if extensions.is_empty() {
+        return None;
+    }
+    let features: Vec<String> = extensions
+        .iter()
+        .map(|e| format!("feature = {e:?}"))
+        .collect();
+    Some(if features.len() == 1 {
+        format!("#[cfg({})]\n", features[0])
+    } else {
+        format!("#[cfg(any({}))]\n", features.join(", "))
+    })
+}
+
+fn is_selected(opts: &EmitOptions, extensions: &[String]) -> bool {
+    opts.only.is_empty() || extensions.iter().any(|e| opts.only.contains(e))
+}
+
+fn emit_handles(vo: &VulkanObject, opts: &EmitOptions, out: &mut String) {
+    let mut names: Vec<&String> = vo.handles.keys().collect();
+    names.sort();
+    for name in names {
+        let handle = &vo.handles[name];
+        if !is_selected(opts, &handle.extensions) {
+            continue;
+        }
+        if let Some(guard) = cfg_guard(&handle.extensions) {
+            out.push_str(&guard);
+        }
+        let _ = writeln!(out, "#[repr(transparent)]");
+        let _ = writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]");
+        if handle.dispatchable {
+            let _ = writeln!(out, "pub struct {name}(pub *mut std::ffi::c_void);");
+        } else {
+            let _ = writeln!(out, "pub struct {name}(pub u64);");
+        }
+    }
+}
+
+fn emit_enum(vo: &VulkanObject, opts: &EmitOptions, out: &mut String) {
+    let mut names: Vec<&String> = vo.enums.keys().collect();
+    names.sort();
+    for name in names {
+        let e = &vo.enums[name];
+        if !is_selected(opts, &e.extensions) {
+            continue;
+        }
+        if let Some(guard) = cfg_guard(&e.extensions) {
+            out.push_str(&guard);
+        }
+        let _ = writeln!(out, "#[repr(i32)]");
+        let _ = writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]");
+        let _ = writeln!(out, "pub enum {name} {{");
+        for field in &e.fields {
+            let _ = writeln!(out, "    {} = {},", field.name, field.value);
+        }
+        let _ = writeln!(out, "}}");
+    }
+}
+
+fn emit_bitmasks(vo: &VulkanObject, opts: &EmitOptions, out: &mut String) {
+    let mut names: Vec<&String> = vo.bitmasks.keys().collect();
+    names.sort();
+    for name in names {
+        let bitmask = &vo.bitmasks[name];
+        if !is_selected(opts, &bitmask.extensions) {
+            continue;
+        }
+        if let Some(guard) = cfg_guard(&bitmask.extensions) {
+            out.push_str(&guard);
+        }
+        let repr = if bitmask.bit_width == 64 { "u64" } else { "u32" };
+        let _ = writeln!(out, "#[repr(transparent)]");
+        let _ = writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]");
+        let _ = writeln!(out, "pub struct {} (pub {repr});", bitmask.flag_name);
+        let _ = writeln!(out, "impl {} {{", bitmask.flag_name);
+        for flag in &bitmask.flags {
+            let _ = writeln!(
+                out,
+                "    pub const {}: {} = {}({repr_value});",
+                flag.name,
+                bitmask.flag_name,
+                bitmask.flag_name,
+                repr_value = flag.value,
+            );
+        }
+        let _ = writeln!(out, "}}");
+    }
+}
+
+/// Map a `Member::type_` C base type spelling to the Rust type FFI bindings
+/// use for it. Names not listed here (`VkFormat`, `VkBuffer`, other structs
+/// and enums) already match their emitted Rust name and pass through as-is.
+fn c_base_type_to_rust(c_type: &str) -> &str {
+    match c_type {
+        "void" => "std::ffi::c_void",
+        "char" => "std::ffi::c_char",
+        "float" => "f32",
+        "double" => "f64",
+        "int8_t" => "i8",
+        "uint8_t" => "u8",
+        "int16_t" => "i16",
+        "uint16_t" => "u16",
+        "int32_t" | "int" => "i32",
+        "uint32_t" => "u32",
+        "int64_t" => "i64",
+        "uint64_t" => "u64",
+        "size_t" => "usize",
+        other => other,
+    }
+}
+
+fn emit_structs(vo: &VulkanObject, opts: &EmitOptions, out: &mut String) {
+    let mut names: Vec<&String> = vo.structs.keys().collect();
+    names.sort();
+    for name in names {
+        let s = &vo.structs[name];
+        if !is_selected(opts, &s.extensions) {
+            continue;
+        }
+        if let Some(guard) = cfg_guard(&s.extensions) {
+            out.push_str(&guard);
+        }
+        let _ = writeln!(out, "#[repr(C)]");
+        let _ = writeln!(out, "#[derive(Debug, Clone, Copy)]");
+        let keyword = if s.union { "union" } else { "struct" };
+        let _ = writeln!(out, "pub {keyword} {name} {{");
+        for member in &s.members {
+            let base_ty = c_base_type_to_rust(&member.type_);
+            let mut rust_ty = if member.pointer {
+                format!("*const {base_ty}")
+            } else {
+                base_ty.to_string()
+            };
+            for dim in member.fixed_size_array.iter().rev() {
+                rust_ty = format!("[{rust_ty}; {dim}]");
+            }
+            let _ = writeln!(out, "    pub {}: {rust_ty},", member.name);
+        }
+        let _ = writeln!(out, "}}");
+    }
+}
+
+fn emit_constants(vo: &VulkanObject, out: &mut String) {
+    let mut names: Vec<&String> = vo.constants.keys().collect();
+    names.sort();
+    for name in names {
+        let constant = &vo.constants[name];
+        let value = match &constant.value {
+            crate::vulkan_object::ConstantValue::Int(i) => i.to_string(),
+            crate::vulkan_object::ConstantValue::Float(f) => format!("{f}f32"),
+        };
+        let _ = writeln!(out, "pub const {name}: {} = {value};", constant.type_);
+    }
+}
+
+/// Per-extension function-pointer loader, resolved via a user-supplied
+/// `get_*_proc_addr`-style closure rather than static linking.
+fn emit_command_loaders(vo: &VulkanObject, opts: &EmitOptions, out: &mut String) {
+    let mut by_extension: std::collections::BTreeMap<String, Vec<&String>> = std::collections::BTreeMap::new();
+    for (name, command) in &vo.commands {
+        if !is_selected(opts, &command.extensions) {
+            continue;
+        }
+        let key = command
+            .extensions
+            .first()
+            .cloned()
+            .unwrap_or_else(|| "core".to_string());
+        by_extension.entry(key).or_default().push(name);
+    }
+
+    for (extension, mut commands) in by_extension {
+        commands.sort();
+        let struct_name = format!("{}Fn", to_pascal_case(&extension));
+        let _ = writeln!(out, "pub struct {struct_name} {{");
+        for name in &commands {
+            let command = &vo.commands[*name];
+            // command.c_function_pointer is the C typedef; loaders store the
+            // resolved symbol as an untyped function pointer and callers cast
+            // it back via the typedef when invoking.
+            let _ = writeln!(out, "    /// {}", command.c_function_pointer);
+            let _ = writeln!(out, "    pub {name}: *const std::ffi::c_void,");
+        }
+        let _ = writeln!(out, "}}");
+        let _ = writeln!(out, "impl {struct_name} {{");
+        let _ = writeln!(
+            out,
+            "    pub fn load(mut get_proc_addr: impl FnMut(&str) -> *const std::ffi::c_void) -> Self {{"
+        );
+        let _ = writeln!(out, "        Self {{");
+        for name in &commands {
+            let _ = writeln!(out, "            {name}: get_proc_addr(\"{name}\"),");
+        }
+        let _ = writeln!(out, "        }}");
+        let _ = writeln!(out, "    }}");
+        let _ = writeln!(out, "}}");
+    }
+}
+
+fn to_pascal_case(name: &str) -> String {
+    name.split(['_', '-'])
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let mut chars = s.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Emit a complete set of Rust FFI bindings for `registry`, gated per `opts`.
+pub fn emit_rust(registry: &VulkanObject, opts: &EmitOptions) -> String {
+    let mut out = String::new();
+    out.push_str("// @generated by vulkan_object::codegen::emit_rust\n\n");
+    emit_handles(registry, opts, &mut out);
+    emit_enum(registry, opts, &mut out);
+    emit_bitmasks(registry, opts, &mut out);
+    emit_structs(registry, opts, &mut out);
+    emit_constants(registry, &mut out);
+    emit_command_loaders(registry, opts, &mut out);
+    out
+}
@@ -0,0 +1,256 @@
+// Copyright 2023-2026 The Khronos Group Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Parser and evaluator for the `depends` boolean-expression mini-language
+//! used by `Extension.depends`, `FeatureRequirement.depends`,
+//! `SyncPipeline.depends`, and `VideoRequiredCapabilities.value`.
+//!
+//! Grammar (loosest to tightest binding):
+//!   expr   := or
+//!   or     := and (',' and)*          -- ',' is OR
+//!   and    := leaf ('+' leaf)*        -- '+' is AND, binds tighter than ','
+//!   leaf   := '(' expr ')' | token
+//!   token  := version | extension | struct '::' field
+
+use std::collections::HashSet;
+use std::fmt;
+
+/// A parsed `depends` expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DependsExpr {
+    And(Vec<DependsExpr>),
+    Or(Vec<DependsExpr>),
+    /// ex) `VK_VERSION_1_2`
+    Version(String),
+    /// ex) `VK_EXT_descriptor_indexing`
+    Extension(String),
+    /// ex) `VkPhysicalDeviceVulkan12Features::descriptorIndexing`
+    Feature { struct_: String, field: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to parse depends expression: {}", self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn token_to_expr(token: &str) -> DependsExpr {
+    let token = token.trim();
+    if let Some((struct_, field)) = token.split_once("::") {
+        DependsExpr::Feature {
+            struct_: struct_.trim().to_string(),
+            field: field.trim().to_string(),
+        }
+    } else if token.starts_with("VK_VERSION_") {
+        DependsExpr::Version(token.to_string())
+    } else {
+        DependsExpr::Extension(token.to_string())
+    }
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+    src: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str) -> Self {
+        Parser {
+            chars: src.char_indices().peekable(),
+            src,
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<DependsExpr, ParseError> {
+        let mut terms = vec![self.parse_and()?];
+        while self.peek_char() == Some(',') {
+            self.chars.next();
+            terms.push(self.parse_and()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.pop().unwrap()
+        } else {
+            DependsExpr::Or(terms)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<DependsExpr, ParseError> {
+        let mut terms = vec![self.parse_leaf()?];
+        while self.peek_char() == Some('+') {
+            self.chars.next();
+            terms.push(self.parse_leaf()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.pop().unwrap()
+        } else {
+            DependsExpr::And(terms)
+        })
+    }
+
+    fn parse_leaf(&mut self) -> Result<DependsExpr, ParseError> {
+        match self.peek_char() {
+            Some('(') => {
+                self.chars.next();
+                let inner = self.parse_or()?;
+                match self.peek_char() {
+                    Some(')') => {
+                        self.chars.next();
+                        Ok(inner)
+                    }
+                    _ => Err(ParseError {
+                        message: format!("unbalanced parentheses in `{}`", self.src),
+                    }),
+                }
+            }
+            Some(_) => Ok(token_to_expr(self.take_token())),
+            None => Err(ParseError {
+                message: format!("unexpected end of expression in `{}`", self.src),
+            }),
+        }
+    }
+
+    fn peek_char(&mut self) -> Option<char> {
+        self.chars.peek().map(|&(_, c)| c)
+    }
+
+    fn take_token(&mut self) -> &'a str {
+        let start = self.chars.peek().map(|&(i, _)| i).unwrap_or(self.src.len());
+        let mut end = start;
+        while let Some(&(i, c)) = self.chars.peek() {
+            if c == ',' || c == '+' || c == '(' || c == ')' {
+                break;
+            }
+            end = i + c.len_utf8();
+            self.chars.next();
+        }
+        &self.src[start..end]
+    }
+}
+
+impl DependsExpr {
+    /// Parse a `depends` string. An empty or all-whitespace string parses to
+    /// an always-true expression (represented as an empty `And`).
+    pub fn parse(s: &str) -> Result<DependsExpr, ParseError> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Ok(DependsExpr::And(Vec::new()));
+        }
+        let mut parser = Parser::new(trimmed);
+        let expr = parser.parse_or()?;
+        if parser.peek_char().is_some() {
+            return Err(ParseError {
+                message: format!("unexpected trailing input in `{trimmed}`"),
+            });
+        }
+        Ok(expr)
+    }
+
+    /// Parse an `Option<String>` depends field, treating `None` the same as
+    /// an empty string: always true.
+    pub fn parse_opt(s: Option<&str>) -> Result<DependsExpr, ParseError> {
+        Self::parse(s.unwrap_or(""))
+    }
+
+    /// Evaluate this expression against the caller's enabled versions,
+    /// extensions, and `(struct, field)` features.
+    pub fn satisfied_by(
+        &self,
+        versions: &HashSet<String>,
+        extensions: &HashSet<String>,
+        features: &HashSet<(String, String)>,
+    ) -> bool {
+        match self {
+            DependsExpr::And(terms) => terms.iter().all(|t| t.satisfied_by(versions, extensions, features)),
+            DependsExpr::Or(terms) => terms.iter().any(|t| t.satisfied_by(versions, extensions, features)),
+            DependsExpr::Version(v) => versions.contains(v),
+            DependsExpr::Extension(e) => extensions.contains(e),
+            DependsExpr::Feature { struct_, field } => {
+                features.contains(&(struct_.clone(), field.clone()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_is_always_true() {
+        let expr = DependsExpr::parse("").unwrap();
+        assert!(expr.satisfied_by(&HashSet::new(), &HashSet::new(), &HashSet::new()));
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        // a+b,c  =>  (a AND b) OR c
+        let expr = DependsExpr::parse("VK_VERSION_1_1+VK_EXT_a,VK_EXT_b").unwrap();
+        assert_eq!(
+            expr,
+            DependsExpr::Or(vec![
+                DependsExpr::And(vec![
+                    DependsExpr::Version("VK_VERSION_1_1".to_string()),
+                    DependsExpr::Extension("VK_EXT_a".to_string()),
+                ]),
+                DependsExpr::Extension("VK_EXT_b".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn parses_feature_token() {
+        let expr =
+            DependsExpr::parse("VK_VERSION_1_2+VkPhysicalDeviceVulkan12Features::descriptorIndexing")
+                .unwrap();
+        assert_eq!(
+            expr,
+            DependsExpr::And(vec![
+                DependsExpr::Version("VK_VERSION_1_2".to_string()),
+                DependsExpr::Feature {
+                    struct_: "VkPhysicalDeviceVulkan12Features".to_string(),
+                    field: "descriptorIndexing".to_string(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn parses_parenthesized_groups() {
+        let expr = DependsExpr::parse("VK_VERSION_1_1+(VK_KHR_foo,VK_KHR_bar)").unwrap();
+        assert_eq!(
+            expr,
+            DependsExpr::And(vec![
+                DependsExpr::Version("VK_VERSION_1_1".to_string()),
+                DependsExpr::Or(vec![
+                    DependsExpr::Extension("VK_KHR_foo".to_string()),
+                    DependsExpr::Extension("VK_KHR_bar".to_string()),
+                ]),
+            ])
+        );
+    }
+
+    #[test]
+    fn trims_whitespace_around_tokens() {
+        let expr = DependsExpr::parse(" VK_EXT_a , VK_EXT_b ").unwrap();
+        assert_eq!(
+            expr,
+            DependsExpr::Or(vec![
+                DependsExpr::Extension("VK_EXT_a".to_string()),
+                DependsExpr::Extension("VK_EXT_b".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn rejects_unbalanced_parens() {
+        assert!(DependsExpr::parse("(VK_EXT_a").is_err());
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2023-2026 The Khronos Group Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resolving the scattered `promotedTo`/`deprecatedBy`/`obsoletedBy` fields
+//! on [`Extension`] plus [`Legacy`] on [`Command`] into a single status.
+
+use crate::vulkan_object::{Legacy, Version, VulkanObject};
+
+/// The deprecation status of an extension or command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeprecationStatus {
+    /// Neither deprecated, promoted, nor obsoleted.
+    Current,
+    /// Promoted into a later core version or another extension.
+    PromotedTo(String),
+    /// Deprecated in favor of another extension, or with no replacement.
+    DeprecatedBy(Option<String>),
+    /// No longer usable at all; superseded by the named extension.
+    Obsoleted(String),
+    /// Deprecated via the spec's `<deprecate>` mechanism (`Command.legacy`).
+    Legacy {
+        link: Option<String>,
+        replacement_version: Option<Version>,
+    },
+}
+
+impl From<&Legacy> for DeprecationStatus {
+    fn from(legacy: &Legacy) -> Self {
+        DeprecationStatus::Legacy {
+            link: legacy.link.clone(),
+            replacement_version: legacy.version.as_deref().cloned(),
+        }
+    }
+}
+
+impl VulkanObject {
+    /// Resolve the deprecation status of `name`, which may be an extension
+    /// name or a command name (via `Command.legacy`).
+    pub fn deprecation(&self, name: &str) -> DeprecationStatus {
+        if let Some(extension) = self.extensions.get(name) {
+            if let Some(obsoleted_by) = &extension.obsoleted_by {
+                return DeprecationStatus::Obsoleted(obsoleted_by.clone());
+            }
+            if let Some(promoted_to) = &extension.promoted_to {
+                return DeprecationStatus::PromotedTo(promoted_to.clone());
+            }
+            if extension.deprecated_by.is_some() {
+                return DeprecationStatus::DeprecatedBy(extension.deprecated_by.clone());
+            }
+            return DeprecationStatus::Current;
+        }
+
+        if let Some(command) = self.commands.get(name) {
+            if let Some(legacy) = &command.legacy {
+                return DeprecationStatus::from(legacy.as_ref());
+            }
+        }
+
+        DeprecationStatus::Current
+    }
+
+    /// Follow `promotedTo` chains starting from `name` to the final core
+    /// version it ended up in, if any.
+    pub fn final_promoted_version(&self, name: &str) -> Option<String> {
+        let mut current = name.to_string();
+        let mut visited = std::collections::HashSet::new();
+        loop {
+            if !visited.insert(current.clone()) {
+                // A cycle in promotedTo would be a malformed registry; bail out.
+                return None;
+            }
+            match self.extensions.get(&current).and_then(|e| e.promoted_to.clone()) {
+                Some(next) => current = next,
+                None => {
+                    return if current.starts_with("VK_VERSION_") {
+                        Some(current)
+                    } else {
+                        None
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,416 @@
+// Copyright 2023-2026 The Khronos Group Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Diffing two loaded [`VulkanObject`]s across header versions, e.g. for the
+//! "Update Vulkan-Headers to 1.3.x" bumps every downstream consumer carries.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+
+use crate::vulkan_object::{Bitmask, Command, Enum, Param, Struct, VulkanObject};
+
+/// Build a map from every known name (canonical + aliases) to the canonical
+/// name, so a rename isn't reported as a remove+add.
+fn alias_index<'a>(names: impl Iterator<Item = (&'a str, &'a [String])>) -> HashMap<&'a str, &'a str> {
+    let mut index = HashMap::new();
+    for (name, aliases) in names {
+        index.insert(name, name);
+        for alias in aliases {
+            index.insert(alias.as_str(), name);
+        }
+    }
+    index
+}
+
+/// Resolve `key` (a name in the old registry) to whatever canonical name it's
+/// known by in `new_index`, falling back to `key`'s own canonical name if the
+/// new registry doesn't mention it under any alias.
+fn resolve<'a>(
+    key: &'a str,
+    old_index: &HashMap<&'a str, &'a str>,
+    new_index: &HashMap<&'a str, &'a str>,
+) -> &'a str {
+    let canonical = old_index.get(key).copied().unwrap_or(key);
+    new_index.get(canonical).copied().unwrap_or(canonical)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: String,
+    pub new: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EnumDiff {
+    pub name: String,
+    pub added_fields: Vec<String>,
+    pub removed_fields: Vec<String>,
+    pub changed_values: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BitmaskDiff {
+    pub name: String,
+    pub added_flags: Vec<String>,
+    pub removed_flags: Vec<String>,
+    pub changed_values: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StructDiff {
+    pub name: String,
+    pub added_members: Vec<String>,
+    pub removed_members: Vec<String>,
+    /// Members present in both versions whose position among the members
+    /// common to both versions changed.
+    pub reordered_members: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CommandDiff {
+    pub name: String,
+    pub added_params: Vec<String>,
+    pub removed_params: Vec<String>,
+    /// Params present in both versions whose `fullType` changed.
+    pub changed_param_types: Vec<FieldChange>,
+    pub changed_return_type: Option<FieldChange>,
+    pub added_success_codes: Vec<String>,
+    pub removed_success_codes: Vec<String>,
+    pub added_error_codes: Vec<String>,
+    pub removed_error_codes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RegistryDiff {
+    pub added_extensions: Vec<String>,
+    pub removed_extensions: Vec<String>,
+    pub promoted_extensions: Vec<FieldChange>,
+
+    pub added_commands: Vec<String>,
+    pub removed_commands: Vec<String>,
+    pub modified_commands: Vec<CommandDiff>,
+
+    pub added_structs: Vec<String>,
+    pub removed_structs: Vec<String>,
+    pub modified_structs: Vec<StructDiff>,
+
+    pub added_enums: Vec<String>,
+    pub removed_enums: Vec<String>,
+    pub modified_enums: Vec<EnumDiff>,
+
+    pub added_bitmasks: Vec<String>,
+    pub removed_bitmasks: Vec<String>,
+    pub modified_bitmasks: Vec<BitmaskDiff>,
+}
+
+fn diff_enum(old: &Enum, new: &Enum) -> Option<EnumDiff> {
+    let old_fields: HashMap<&str, i64> = old.fields.iter().map(|f| (f.name.as_str(), f.value)).collect();
+    let new_fields: HashMap<&str, i64> = new.fields.iter().map(|f| (f.name.as_str(), f.value)).collect();
+
+    let added_fields: Vec<String> = new_fields
+        .keys()
+        .filter(|k| !old_fields.contains_key(*k))
+        .map(|k| k.to_string())
+        .collect();
+    let removed_fields: Vec<String> = old_fields
+        .keys()
+        .filter(|k| !new_fields.contains_key(*k))
+        .map(|k| k.to_string())
+        .collect();
+    let changed_values: Vec<FieldChange> = old_fields
+        .iter()
+        .filter_map(|(name, old_value)| {
+            let new_value = new_fields.get(name)?;
+            (old_value != new_value).then(|| FieldChange {
+                field: name.to_string(),
+                old: old_value.to_string(),
+                new: new_value.to_string(),
+            })
+        })
+        .collect();
+
+    if added_fields.is_empty() && removed_fields.is_empty() && changed_values.is_empty() {
+        return None;
+    }
+    Some(EnumDiff {
+        name: new.name.clone(),
+        added_fields,
+        removed_fields,
+        changed_values,
+    })
+}
+
+fn diff_bitmask(old: &Bitmask, new: &Bitmask) -> Option<BitmaskDiff> {
+    let old_flags: HashMap<&str, u64> = old.flags.iter().map(|f| (f.name.as_str(), f.value)).collect();
+    let new_flags: HashMap<&str, u64> = new.flags.iter().map(|f| (f.name.as_str(), f.value)).collect();
+
+    let added_flags: Vec<String> = new_flags
+        .keys()
+        .filter(|k| !old_flags.contains_key(*k))
+        .map(|k| k.to_string())
+        .collect();
+    let removed_flags: Vec<String> = old_flags
+        .keys()
+        .filter(|k| !new_flags.contains_key(*k))
+        .map(|k| k.to_string())
+        .collect();
+    let changed_values: Vec<FieldChange> = old_flags
+        .iter()
+        .filter_map(|(name, old_value)| {
+            let new_value = new_flags.get(name)?;
+            (old_value != new_value).then(|| FieldChange {
+                field: name.to_string(),
+                old: old_value.to_string(),
+                new: new_value.to_string(),
+            })
+        })
+        .collect();
+
+    if added_flags.is_empty() && removed_flags.is_empty() && changed_values.is_empty() {
+        return None;
+    }
+    Some(BitmaskDiff {
+        name: new.name.clone(),
+        added_flags,
+        removed_flags,
+        changed_values,
+    })
+}
+
+fn diff_struct(old: &Struct, new: &Struct) -> Option<StructDiff> {
+    let old_names: Vec<&str> = old.members.iter().map(|m| m.name.as_str()).collect();
+    let new_names: Vec<&str> = new.members.iter().map(|m| m.name.as_str()).collect();
+    let old_set: HashSet<&str> = old_names.iter().copied().collect();
+    let new_set: HashSet<&str> = new_names.iter().copied().collect();
+
+    let added_members: Vec<String> = new_names
+        .iter()
+        .filter(|n| !old_set.contains(*n))
+        .map(|n| n.to_string())
+        .collect();
+    let removed_members: Vec<String> = old_names
+        .iter()
+        .filter(|n| !new_set.contains(*n))
+        .map(|n| n.to_string())
+        .collect();
+    let common_old: Vec<&str> = old_names.iter().copied().filter(|n| new_set.contains(n)).collect();
+    let common_new: Vec<&str> = new_names.iter().copied().filter(|n| old_set.contains(n)).collect();
+    let reordered_members: Vec<String> = common_new
+        .iter()
+        .zip(common_old.iter())
+        .filter(|(new_name, old_name)| new_name != old_name)
+        .map(|(new_name, _)| new_name.to_string())
+        .collect();
+
+    if added_members.is_empty() && removed_members.is_empty() && reordered_members.is_empty() {
+        return None;
+    }
+    Some(StructDiff {
+        name: new.name.clone(),
+        added_members,
+        removed_members,
+        reordered_members,
+    })
+}
+
+fn diff_code_sets(old: &[String], new: &[String]) -> (Vec<String>, Vec<String>) {
+    let old_set: HashSet<&str> = old.iter().map(String::as_str).collect();
+    let new_set: HashSet<&str> = new.iter().map(String::as_str).collect();
+    let added = new_set.iter().filter(|c| !old_set.contains(*c)).map(|c| c.to_string()).collect();
+    let removed = old_set.iter().filter(|c| !new_set.contains(*c)).map(|c| c.to_string()).collect();
+    (added, removed)
+}
+
+fn diff_command(old: &Command, new: &Command) -> Option<CommandDiff> {
+    let old_params: HashMap<&str, &Param> = old.params.iter().map(|p| (p.name.as_str(), p)).collect();
+    let new_params: HashMap<&str, &Param> = new.params.iter().map(|p| (p.name.as_str(), p)).collect();
+
+    let added_params: Vec<String> = new_params
+        .keys()
+        .filter(|p| !old_params.contains_key(*p))
+        .map(|p| p.to_string())
+        .collect();
+    let removed_params: Vec<String> = old_params
+        .keys()
+        .filter(|p| !new_params.contains_key(*p))
+        .map(|p| p.to_string())
+        .collect();
+    let changed_param_types: Vec<FieldChange> = old_params
+        .iter()
+        .filter_map(|(name, old_param)| {
+            let new_param = new_params.get(name)?;
+            (old_param.full_type != new_param.full_type).then(|| FieldChange {
+                field: name.to_string(),
+                old: old_param.full_type.clone(),
+                new: new_param.full_type.clone(),
+            })
+        })
+        .collect();
+    let changed_return_type = (old.return_type != new.return_type).then(|| FieldChange {
+        field: "returnType".to_string(),
+        old: old.return_type.clone(),
+        new: new.return_type.clone(),
+    });
+    let (added_success_codes, removed_success_codes) = diff_code_sets(&old.success_codes, &new.success_codes);
+    let (added_error_codes, removed_error_codes) = diff_code_sets(&old.error_codes, &new.error_codes);
+
+    if added_params.is_empty()
+        && removed_params.is_empty()
+        && changed_param_types.is_empty()
+        && changed_return_type.is_none()
+        && added_success_codes.is_empty()
+        && removed_success_codes.is_empty()
+        && added_error_codes.is_empty()
+        && removed_error_codes.is_empty()
+    {
+        return None;
+    }
+    Some(CommandDiff {
+        name: new.name.clone(),
+        added_params,
+        removed_params,
+        changed_param_types,
+        changed_return_type,
+        added_success_codes,
+        removed_success_codes,
+        added_error_codes,
+        removed_error_codes,
+    })
+}
+
+impl VulkanObject {
+    /// Compare `self` (the older registry) against `other` (the newer one),
+    /// reporting additions, removals, and field-level modifications.
+    /// Renames are resolved via each entity's `aliases` list so a struct or
+    /// enum that was merely promoted under a new canonical name isn't
+    /// reported as a remove+add pair.
+    pub fn diff(&self, other: &VulkanObject) -> RegistryDiff {
+        let old_struct_index = alias_index(self.structs.values().map(|s| (s.name.as_str(), s.aliases.as_slice())));
+        let new_struct_index = alias_index(other.structs.values().map(|s| (s.name.as_str(), s.aliases.as_slice())));
+        let old_enum_index = alias_index(self.enums.values().map(|e| (e.name.as_str(), e.aliases.as_slice())));
+        let new_enum_index = alias_index(other.enums.values().map(|e| (e.name.as_str(), e.aliases.as_slice())));
+        let old_bitmask_index =
+            alias_index(self.bitmasks.values().map(|b| (b.name.as_str(), b.aliases.as_slice())));
+        let new_bitmask_index =
+            alias_index(other.bitmasks.values().map(|b| (b.name.as_str(), b.aliases.as_slice())));
+
+        let (added_extensions, removed_extensions) = diff_key_sets(&self.extensions, &other.extensions);
+        let promoted_extensions = self
+            .extensions
+            .iter()
+            .filter_map(|(name, old)| {
+                let new = other.extensions.get(name)?;
+                (old.promoted_to != new.promoted_to).then(|| FieldChange {
+                    field: name.clone(),
+                    old: old.promoted_to.clone().unwrap_or_default(),
+                    new: new.promoted_to.clone().unwrap_or_default(),
+                })
+            })
+            .collect();
+
+        let (added_commands, removed_commands) = diff_key_sets(&self.commands, &other.commands);
+        let modified_commands = self
+            .commands
+            .iter()
+            .filter_map(|(name, old)| diff_command(old, other.commands.get(name)?))
+            .collect();
+
+        let (added_structs, removed_structs) =
+            diff_renamed_key_sets(&self.structs, &other.structs, &old_struct_index, &new_struct_index);
+        let modified_structs = self
+            .structs
+            .keys()
+            .filter_map(|name| {
+                let new_name = resolve(name, &old_struct_index, &new_struct_index);
+                diff_struct(&self.structs[name], other.structs.get(new_name)?)
+            })
+            .collect();
+
+        let (added_enums, removed_enums) =
+            diff_renamed_key_sets(&self.enums, &other.enums, &old_enum_index, &new_enum_index);
+        let modified_enums = self
+            .enums
+            .keys()
+            .filter_map(|name| {
+                let new_name = resolve(name, &old_enum_index, &new_enum_index);
+                diff_enum(&self.enums[name], other.enums.get(new_name)?)
+            })
+            .collect();
+
+        let (added_bitmasks, removed_bitmasks) = diff_renamed_key_sets(
+            &self.bitmasks,
+            &other.bitmasks,
+            &old_bitmask_index,
+            &new_bitmask_index,
+        );
+        let modified_bitmasks = self
+            .bitmasks
+            .keys()
+            .filter_map(|name| {
+                let new_name = resolve(name, &old_bitmask_index, &new_bitmask_index);
+                diff_bitmask(&self.bitmasks[name], other.bitmasks.get(new_name)?)
+            })
+            .collect();
+
+        RegistryDiff {
+            added_extensions,
+            removed_extensions,
+            promoted_extensions,
+            added_commands,
+            removed_commands,
+            modified_commands,
+            added_structs,
+            removed_structs,
+            modified_structs,
+            added_enums,
+            removed_enums,
+            modified_enums,
+            added_bitmasks,
+            removed_bitmasks,
+            modified_bitmasks,
+        }
+    }
+}
+
+fn diff_key_sets<V>(old: &HashMap<String, V>, new: &HashMap<String, V>) -> (Vec<String>, Vec<String>) {
+    let added = new.keys().filter(|k| !old.contains_key(*k)).cloned().collect();
+    let removed = old.keys().filter(|k| !new.contains_key(*k)).cloned().collect();
+    (added, removed)
+}
+
+/// Every name (canonical + aliases) that `index` resolves to `canonical`.
+fn names_for<'a>(index: &HashMap<&'a str, &'a str>, canonical: &str) -> Vec<&'a str> {
+    index.iter().filter(|(_, v)| **v == canonical).map(|(k, _)| *k).collect()
+}
+
+fn diff_renamed_key_sets<V>(
+    old: &HashMap<String, V>,
+    new: &HashMap<String, V>,
+    old_index: &HashMap<&str, &str>,
+    new_index: &HashMap<&str, &str>,
+) -> (Vec<String>, Vec<String>) {
+    // A new entry is only "added" if none of its names (its canonical name,
+    // plus every alias the new registry knows it by) are recognized under
+    // any name by the old registry -- not just its own canonical name, which
+    // would miss a pure rename/promotion (old canonical becomes a new alias).
+    let added = new
+        .keys()
+        .filter(|k| {
+            let canonical = new_index.get(k.as_str()).copied().unwrap_or(k.as_str());
+            !names_for(new_index, canonical).iter().any(|name| old_index.contains_key(name))
+        })
+        .cloned()
+        .collect();
+    let removed = old
+        .keys()
+        .filter(|k| {
+            let canonical = old_index.get(k.as_str()).copied().unwrap_or(k.as_str());
+            !names_for(old_index, canonical).iter().any(|name| new_index.contains_key(name))
+        })
+        .cloned()
+        .collect();
+    (added, removed)
+}
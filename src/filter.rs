@@ -0,0 +1,80 @@
+// Copyright 2023-2026 The Khronos Group Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Writing a [`VulkanObject`] back out as JSON, including a minimized subset
+//! built from a dependency closure (see [`crate::index`]).
+
+use std::io::{self, Write};
+
+use crate::vulkan_object::VulkanObject;
+
+/// Serialize `vo` as JSON into `w`.
+pub fn save_vulkan_object_to_writer<W: Write>(vo: &VulkanObject, w: W) -> serde_json::Result<()> {
+    serde_json::to_writer(w, vo)
+}
+
+/// Serialize `vo` as a JSON `String`.
+///
+/// The registry is well over a megabyte, and `serde_json::to_string` grows
+/// its buffer one small reallocation at a time. The serialized size scales
+/// with the number of top-level entries, so pre-size the buffer from a rough
+/// average-bytes-per-entry estimate instead of starting from zero.
+pub fn to_json_string(vo: &VulkanObject) -> serde_json::Result<String> {
+    const AVG_BYTES_PER_ENTRY: usize = 512;
+    let entry_count = vo.commands.len()
+        + vo.structs.len()
+        + vo.handles.len()
+        + vo.enums.len()
+        + vo.bitmasks.len()
+        + vo.flags.len()
+        + vo.extensions.len();
+    let mut buf = Vec::with_capacity(entry_count * AVG_BYTES_PER_ENTRY);
+    save_vulkan_object_to_writer(vo, &mut buf)?;
+    // `serde_json` only ever writes valid UTF-8.
+    Ok(String::from_utf8(buf).expect("serde_json output is always valid UTF-8"))
+}
+
+/// Build a new [`VulkanObject`] containing only the commands, structs,
+/// handles, enums, bitmasks and flags named in `closure`, e.g. the output of
+/// [`VulkanObject::resolve_dependencies`]. `entry_points` are additionally
+/// kept even if they don't appear in the closure (resolve_dependencies
+/// excludes the entry point itself). Extensions are kept only if they enable
+/// a kept command, struct or handle; all other tables are copied unchanged.
+pub fn filtered_vulkan_object(
+    vo: &VulkanObject,
+    entry_points: &[&str],
+    closure: &std::collections::BTreeSet<String>,
+) -> VulkanObject {
+    let keep = |name: &str| entry_points.contains(&name) || closure.contains(name);
+
+    let mut filtered = vo.clone();
+    filtered.commands.retain(|name, _| keep(name));
+    filtered.structs.retain(|name, _| keep(name));
+    filtered.handles.retain(|name, _| keep(name));
+    filtered.enums.retain(|name, _| keep(name));
+    filtered.bitmasks.retain(|name, _| keep(name));
+    filtered.flags.retain(|name, _| keep(name));
+
+    let kept_extensions: std::collections::HashSet<&str> = filtered
+        .commands
+        .values()
+        .flat_map(|c| c.extensions.iter())
+        .chain(filtered.structs.values().flat_map(|s| s.extensions.iter()))
+        .chain(filtered.handles.values().flat_map(|h| h.extensions.iter()))
+        .map(String::as_str)
+        .collect();
+    filtered
+        .extensions
+        .retain(|name, _| kept_extensions.contains(name.as_str()));
+
+    filtered
+}
+
+/// An I/O-friendly wrapper around [`save_vulkan_object_to_writer`] for the
+/// common case of writing directly to a file.
+pub fn save_vulkan_object(vo: &VulkanObject, path: &std::path::Path) -> io::Result<()> {
+    let file = std::fs::File::create(path)?;
+    let writer = io::BufWriter::new(file);
+    save_vulkan_object_to_writer(vo, writer).map_err(io::Error::from)
+}
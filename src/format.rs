@@ -0,0 +1,97 @@
+// Copyright 2023-2026 The Khronos Group Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Memory-layout arithmetic over [`Format`] that every texture-upload and
+//! transcoder path otherwise re-implements from the raw `blockSize`/
+//! `texelsPerBlock`/`blockExtent`/`planes`/`components` fields.
+
+use crate::vulkan_object::{Format, FormatPlane, VulkanObject};
+
+/// Parse `block_extent` (ex. `["1", "1", "1"]`) into `(width, height, depth)`,
+/// defaulting missing components to 1 like an uncompressed format would.
+fn block_extent(format: &Format) -> (u64, u64, u64) {
+    let dim = |i: usize| -> u64 {
+        format
+            .block_extent
+            .get(i)
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(1)
+    };
+    (dim(0), dim(1), dim(2))
+}
+
+impl Format {
+    /// The number of bytes needed to store an image of `width` x `height` x
+    /// `depth` texels in this format, rounding up to whole compressed blocks.
+    pub fn bytes_for_extent(&self, width: u32, height: u32, depth: u32) -> u64 {
+        let (block_w, block_h, block_d) = block_extent(self);
+        let blocks_x = (width as u64).div_ceil(block_w);
+        let blocks_y = (height as u64).div_ceil(block_h);
+        let blocks_z = (depth as u64).div_ceil(block_d);
+        blocks_x * blocks_y * blocks_z * self.block_size as u64
+    }
+
+    /// The per-plane extent of a multi-planar YCbCr format, applying
+    /// `plane`'s width/height divisors to the full-resolution `width`/`height`.
+    pub fn plane_extent(&self, plane: &FormatPlane, width: u32, height: u32) -> (u32, u32) {
+        let w = width / plane.width_divisor.max(1) as u32;
+        let h = height / plane.height_divisor.max(1) as u32;
+        (w, h)
+    }
+
+    /// Look up a component's bit depth by its type (`R`/`G`/`B`/`A`/`D`/`S`),
+    /// ex) `"8"`, `"32"`, or `"compressed"`.
+    pub fn component_bits(&self, ty: &str) -> Option<&str> {
+        self.components
+            .iter()
+            .find(|c| c.type_ == ty)
+            .map(|c| c.bits.as_str())
+    }
+}
+
+impl VulkanObject {
+    /// Reverse lookup from a SPIR-V `ImageFormat` name (ex. `Rgba8`) back to
+    /// the `VkFormat` that declares it via `spirvImageFormat`.
+    pub fn format_by_spirv_image_format(&self, spirv_image_format: &str) -> Option<&Format> {
+        self.formats
+            .values()
+            .find(|f| f.spirv_image_format.as_deref() == Some(spirv_image_format))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uncompressed_format() -> Format {
+        Format {
+            name: "VK_FORMAT_R8G8B8A8_UNORM".to_string(),
+            class_name: "32-bit".to_string(),
+            block_size: 4,
+            texels_per_block: 1,
+            block_extent: vec!["1".to_string(), "1".to_string(), "1".to_string()],
+            packed: None,
+            chroma: None,
+            compressed: None,
+            components: Vec::new(),
+            planes: Vec::new(),
+            spirv_image_format: Some("Rgba8".to_string()),
+        }
+    }
+
+    #[test]
+    fn bytes_for_extent_uncompressed() {
+        let format = uncompressed_format();
+        assert_eq!(format.bytes_for_extent(4, 4, 1), 4 * 4 * 4);
+    }
+
+    #[test]
+    fn bytes_for_extent_rounds_up_compressed_blocks() {
+        let mut format = uncompressed_format();
+        format.block_size = 16;
+        format.block_extent = vec!["4".to_string(), "4".to_string(), "1".to_string()];
+        // 5x5 rounds up to 2x2 blocks of 16 bytes each.
+        assert_eq!(format.bytes_for_extent(5, 5, 1), 2 * 2 * 16);
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2023-2026 The Khronos Group Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Graph queries over an already-loaded [`VulkanObject`], such as computing
+//! the transitive closure of types a command or struct depends on.
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use crate::vulkan_object::{Command, Struct, VulkanObject};
+
+/// Lookup tables built once over a [`VulkanObject`] so repeated queries don't
+/// have to re-scan its `HashMap`s.
+pub struct Index<'a> {
+    commands: HashMap<&'a str, &'a Command>,
+    structs: HashMap<&'a str, &'a Struct>,
+    handle_parents: HashMap<&'a str, &'a str>,
+}
+
+/// Strip `const`, pointer, and array annotations off a `cDeclaration`-derived
+/// type name, leaving just the base type that can be looked up in the index.
+///
+/// `type_` on [`Param`]/[`Member`] is already the base type, so this is a
+/// no-op there; it exists so callers working from `fullType` strings can
+/// normalize them the same way.
+fn base_type_name(type_: &str) -> &str {
+    type_
+        .trim()
+        .trim_start_matches("const ")
+        .split('[')
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches('*')
+        .trim()
+}
+
+impl<'a> Index<'a> {
+    fn new(vo: &'a VulkanObject) -> Self {
+        let commands = vo.commands.iter().map(|(k, v)| (k.as_str(), v)).collect();
+        let structs = vo.structs.iter().map(|(k, v)| (k.as_str(), v)).collect();
+        let handle_parents = vo
+            .handles
+            .iter()
+            .filter_map(|(k, v)| v.parent.as_ref().map(|p| (k.as_str(), p.name.as_str())))
+            .collect();
+        Index {
+            commands,
+            structs,
+            handle_parents,
+        }
+    }
+
+    /// Compute the minimal closure of type names needed to use `name`, which
+    /// may be a command or a struct/union. Follows struct/union members,
+    /// command parameters and return type, and handle parent relationships;
+    /// enum/bitmask leaf types are included but not expanded further since
+    /// they have no further type dependencies.
+    pub fn resolve_dependencies(&self, name: &str) -> BTreeSet<String> {
+        let mut visited: HashSet<&str> = HashSet::new();
+        let mut queue: VecDeque<&str> = VecDeque::new();
+        queue.push_back(name);
+
+        while let Some(current) = queue.pop_front() {
+            if !visited.insert(current) {
+                continue;
+            }
+            if let Some(command) = self.commands.get(current) {
+                for param in &command.params {
+                    queue.push_back(base_type_name(&param.type_));
+                }
+                queue.push_back(base_type_name(&command.return_type));
+            }
+            if let Some(struct_) = self.structs.get(current) {
+                for member in &struct_.members {
+                    queue.push_back(base_type_name(&member.type_));
+                }
+            }
+            if let Some(parent) = self.handle_parents.get(current) {
+                queue.push_back(parent);
+            }
+        }
+
+        // The entry point itself isn't a "dependency" of itself.
+        visited.remove(name);
+        visited.into_iter().map(str::to_owned).collect()
+    }
+}
+
+impl VulkanObject {
+    /// Build an [`Index`] over `self` for repeated dependency queries.
+    ///
+    /// See [`Index::resolve_dependencies`].
+    pub fn build_index(&self) -> Index<'_> {
+        Index::new(self)
+    }
+
+    /// Convenience wrapper around [`Self::build_index`] +
+    /// [`Index::resolve_dependencies`] for a single one-off query.
+    pub fn resolve_dependencies(&self, name: &str) -> BTreeSet<String> {
+        self.build_index().resolve_dependencies(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::base_type_name;
+
+    #[test]
+    fn strips_const_and_pointer() {
+        assert_eq!(base_type_name("const VkFormat*"), "VkFormat");
+        assert_eq!(base_type_name("VkBuffer*"), "VkBuffer");
+        assert_eq!(base_type_name("uint32_t"), "uint32_t");
+    }
+}
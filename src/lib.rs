@@ -1,6 +1,30 @@
+pub mod codegen;
+pub mod depends;
+pub mod deprecation;
+pub mod diff;
+pub mod filter;
+pub mod format;
+pub mod index;
+pub mod pnext;
+pub mod resolve;
+pub mod result;
+pub mod struct_meta;
+pub mod sync;
+pub mod valid_usage;
 pub mod vulkan_object;
 
+#[cfg(test)]
+mod test_support;
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[cfg(feature = "embed-registry")]
 const VK_JSON: &str = include_str!("vk.json");
+#[cfg(feature = "embed-registry")]
+const VK_SCHEMA_JSON: &str = include_str!("vk-schema.json");
 
 pub fn load_vulkan_object_from_json_str(
     s: &str,
@@ -8,17 +32,170 @@ pub fn load_vulkan_object_from_json_str(
     serde_json::from_str(s)
 }
 
+/// Load a registry from an arbitrary `vk.json` on disk, for consumers who
+/// track a different Vulkan header version or a custom/extension-filtered
+/// registry instead of the one bundled via the `embed-registry` feature.
+pub fn load_vulkan_object_from_path(path: &Path) -> io::Result<vulkan_object::VulkanObject> {
+    let s = fs::read_to_string(path)?;
+    load_vulkan_object_from_json_str(&s)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(feature = "embed-registry")]
 pub fn load_vulkan_object() -> vulkan_object::VulkanObject {
     load_vulkan_object_from_json_str(VK_JSON).expect("Failed to parse embedded vk.json")
 }
 
+/// A single failure reported by [`jsonschema`] while validating a document
+/// against `vk-schema.json`.
+#[derive(Debug, Clone)]
+pub struct ValidationError {
+    /// JSON pointer into the document, ex) `/commands/vkCmdDraw/params/0`
+    pub pointer: String,
+    /// JSON pointer into the schema that the document failed to satisfy
+    pub schema_pointer: String,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.pointer, self.message)
+    }
+}
+
+/// Raised by [`load_vulkan_object_validated`] and [`load_vulkan_object_checked`].
+#[derive(Debug)]
+pub enum LoadError {
+    /// The input was not valid JSON, or it satisfied the schema but could not
+    /// be deserialized into a [`vulkan_object::VulkanObject`].
+    Json(serde_json::Error),
+    /// The input parsed as JSON but did not conform to `vk-schema.json`.
+    Schema(Vec<ValidationError>),
+    /// The schema document itself (either the embedded `vk-schema.json` or a
+    /// caller-supplied one passed to [`load_vulkan_object_validated_with_schema`])
+    /// was not valid JSON, or `jsonschema` rejected it as an unsatisfiable schema.
+    InvalidSchema(String),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Json(e) => write!(f, "failed to parse JSON: {e}"),
+            LoadError::Schema(errors) => {
+                writeln!(f, "{} schema validation error(s):", errors.len())?;
+                for error in errors {
+                    writeln!(f, "  {error}")?;
+                }
+                Ok(())
+            }
+            LoadError::InvalidSchema(message) => write!(f, "invalid schema document: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+fn compile_schema(schema_json: &str) -> Result<jsonschema::JSONSchema, LoadError> {
+    let schema: serde_json::Value = serde_json::from_str(schema_json)
+        .map_err(|e| LoadError::InvalidSchema(format!("failed to parse schema JSON: {e}")))?;
+    let draft = schema
+        .get("$schema")
+        .and_then(|v| v.as_str())
+        .map(|s| {
+            if s.contains("2020-12") {
+                jsonschema::Draft::Draft202012
+            } else if s.contains("2019-09") {
+                jsonschema::Draft::Draft201909
+            } else if s.contains("draft-07") {
+                jsonschema::Draft::Draft7
+            } else {
+                jsonschema::Draft::Draft202012
+            }
+        })
+        .unwrap_or(jsonschema::Draft::Draft202012);
+    jsonschema::JSONSchema::options()
+        .with_draft(draft)
+        .compile(&schema)
+        .map_err(|e| LoadError::InvalidSchema(e.to_string()))
+}
+
+/// Validate `s` against `vk-schema.json` and deserialize it into a
+/// [`vulkan_object::VulkanObject`], returning every schema violation at once
+/// instead of panicking on the first `serde_json` error.
+#[cfg(feature = "embed-registry")]
+pub fn load_vulkan_object_validated(
+    s: &str,
+) -> Result<vulkan_object::VulkanObject, LoadError> {
+    load_vulkan_object_validated_with_schema(s, VK_SCHEMA_JSON)
+}
+
+/// Same as [`load_vulkan_object_validated`], but against a caller-supplied
+/// schema document rather than the embedded `vk-schema.json`.
+pub fn load_vulkan_object_validated_with_schema(
+    s: &str,
+    schema_json: &str,
+) -> Result<vulkan_object::VulkanObject, LoadError> {
+    let instance: serde_json::Value = serde_json::from_str(s).map_err(LoadError::Json)?;
+    let compiled = compile_schema(schema_json)?;
+
+    if let Err(errors) = compiled.validate(&instance) {
+        let errors = errors
+            .map(|e| ValidationError {
+                pointer: e.instance_path.to_string(),
+                schema_pointer: e.schema_path.to_string(),
+                message: e.to_string(),
+            })
+            .collect();
+        return Err(LoadError::Schema(errors));
+    }
+
+    serde_json::from_value(instance).map_err(LoadError::Json)
+}
+
+/// Validate and load the embedded `vk.json`.
+///
+/// Unlike [`load_vulkan_object`], this never panics on a malformed registry;
+/// it returns a [`LoadError`] describing every violation instead.
+#[cfg(feature = "embed-registry")]
+pub fn load_vulkan_object_checked() -> Result<vulkan_object::VulkanObject, LoadError> {
+    load_vulkan_object_validated(VK_JSON)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
+    #[cfg(feature = "embed-registry")]
     fn test_load_vulkan_object() {
         let vo = load_vulkan_object();
         assert!(!vo.commands.is_empty());
     }
+
+    #[test]
+    #[cfg(feature = "embed-registry")]
+    fn test_load_vulkan_object_checked() {
+        let vo = load_vulkan_object_checked().expect("embedded vk.json must satisfy its schema");
+        assert!(!vo.commands.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "embed-registry")]
+    fn test_load_vulkan_object_validated_rejects_malformed_json() {
+        let err = load_vulkan_object_validated("{ not json").unwrap_err();
+        assert!(matches!(err, LoadError::Json(_)));
+    }
+
+    #[test]
+    fn load_vulkan_object_validated_with_schema_rejects_unparseable_schema() {
+        let err = load_vulkan_object_validated_with_schema("{}", "{ not json").unwrap_err();
+        assert!(matches!(err, LoadError::InvalidSchema(_)));
+    }
+
+    #[test]
+    fn load_vulkan_object_validated_with_schema_rejects_uncompilable_schema() {
+        // `type` must be a string or array of strings, not a number.
+        let err = load_vulkan_object_validated_with_schema("{}", r#"{"type": 5}"#).unwrap_err();
+        assert!(matches!(err, LoadError::InvalidSchema(_)));
+    }
 }
@@ -0,0 +1,122 @@
+// Copyright 2023-2026 The Khronos Group Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Queries over `Struct.extends`/`extended_by`/`s_type`, treating them as a
+//! graph so consumers can discover valid pNext chains without walking the
+//! whole struct table themselves.
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::vulkan_object::{Struct, VulkanObject};
+
+impl VulkanObject {
+    /// Everything that can chain into `root`'s `pNext`, transitively
+    /// following `extended_by`.
+    pub fn structs_extending(&self, root: &str) -> Vec<&Struct> {
+        let mut visited: HashSet<&str> = HashSet::new();
+        let mut queue: VecDeque<&str> = VecDeque::new();
+        queue.push_back(root);
+        let mut result = Vec::new();
+
+        while let Some(name) = queue.pop_front() {
+            let Some(struct_) = self.structs.get(name) else {
+                continue;
+            };
+            for extender in &struct_.extended_by {
+                if visited.insert(extender.as_str()) {
+                    if let Some(extender_struct) = self.structs.get(extender) {
+                        result.push(extender_struct);
+                    }
+                    queue.push_back(extender);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Like [`Self::structs_extending`], but only keeps extenders whose
+    /// enabling version/extensions are contained in `enabled`.
+    pub fn structs_extending_filtered(
+        &self,
+        root: &str,
+        enabled: &HashSet<&str>,
+    ) -> Vec<&Struct> {
+        self.structs_extending(root)
+            .into_iter()
+            .filter(|s| {
+                let version_ok = s
+                    .version
+                    .as_ref()
+                    .map(|v| enabled.contains(v.name.as_str()))
+                    .unwrap_or(true);
+                let extensions_ok =
+                    s.extensions.is_empty() || s.extensions.iter().any(|e| enabled.contains(e.as_str()));
+                version_ok && extensions_ok
+            })
+            .collect()
+    }
+
+    /// A struct is a pNext "root" (i.e. a binding generator would emit a
+    /// push-next builder for it) when it has an `sType` and at least one
+    /// struct can extend it.
+    pub fn is_root_struct(&self, name: &str) -> bool {
+        self.structs
+            .get(name)
+            .map(|s| s.s_type.is_some() && !s.extended_by.is_empty())
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vulkan_object::Struct;
+    use std::collections::HashMap;
+
+    fn test_struct(name: &str, s_type: Option<&str>, extended_by: &[&str]) -> Struct {
+        Struct {
+            name: name.to_string(),
+            aliases: Vec::new(),
+            extensions: Vec::new(),
+            version: None,
+            protect: None,
+            members: Vec::new(),
+            union: false,
+            returned_only: false,
+            s_type: s_type.map(str::to_string),
+            allow_duplicate: false,
+            extends: Vec::new(),
+            extended_by: extended_by.iter().map(|s| s.to_string()).collect(),
+            video_std_header: None,
+        }
+    }
+
+    #[test]
+    fn walks_extended_by_transitively() {
+        let mut structs = HashMap::new();
+        structs.insert(
+            "VkImageCreateInfo".to_string(),
+            test_struct("VkImageCreateInfo", Some("VK_STRUCTURE_TYPE_IMAGE_CREATE_INFO"), &["VkA"]),
+        );
+        structs.insert("VkA".to_string(), test_struct("VkA", None, &["VkB"]));
+        structs.insert("VkB".to_string(), test_struct("VkB", None, &[]));
+
+        let vo = VulkanObject {
+            structs,
+            ..crate::test_support::empty_vulkan_object()
+        };
+
+        let names: Vec<&str> = vo
+            .structs_extending("VkImageCreateInfo")
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(names.len(), 2);
+        assert!(names.contains(&"VkA"));
+        assert!(names.contains(&"VkB"));
+        assert!(vo.is_root_struct("VkImageCreateInfo"));
+        assert!(!vo.is_root_struct("VkB"));
+    }
+}
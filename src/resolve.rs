@@ -0,0 +1,135 @@
+// Copyright 2023-2026 The Khronos Group Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resolving a requested set of extensions/versions into everything that
+//! must be enabled alongside them, using the `depends` DSL from
+//! [`crate::depends`].
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::depends::{DependsExpr, ParseError};
+use crate::vulkan_object::VulkanObject;
+
+#[derive(Debug)]
+pub enum DepError {
+    Parse(ParseError),
+    /// `depends` referenced a name that isn't a known extension or version.
+    UnknownName(String),
+}
+
+impl fmt::Display for DepError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DepError::Parse(e) => write!(f, "{e}"),
+            DepError::UnknownName(name) => write!(f, "unknown extension or version `{name}`"),
+        }
+    }
+}
+
+impl std::error::Error for DepError {}
+
+/// The transitive closure of a dependency resolution, topologically ordered
+/// so dependencies precede dependents.
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedSet {
+    /// Extension/version names, dependencies first.
+    pub order: Vec<String>,
+    /// For each resolved extension gated behind a platform, the
+    /// `VK_USE_PLATFORM_*` macro a generator should wrap it in.
+    pub platform_guards: HashMap<String, String>,
+}
+
+/// `VK_VERSION_1_0` is the implicit baseline (see [`crate::vulkan_object::Version`]'s
+/// doc comment: it's never an entry in `self.versions`), so it's always
+/// satisfied and never needs to be resolved or enabled.
+const BASELINE_VERSION: &str = "VK_VERSION_1_0";
+
+fn is_known(vo: &VulkanObject, name: &str) -> bool {
+    name == BASELINE_VERSION || vo.extensions.contains_key(name) || vo.versions.contains_key(name)
+}
+
+/// Collect every `Version`/`Extension` leaf reachable through `expr`'s first
+/// *satisfiable* path: for an OR, that's the first branch whose leaves are
+/// all known extensions/versions, falling back to the first branch at all if
+/// none qualify (so a single unresolvable alternative doesn't abort
+/// resolution of the rest of the expression). `Feature` leaves reference
+/// struct members rather than extensions/versions, so they aren't part of
+/// the extension graph.
+fn select_leaves(vo: &VulkanObject, expr: &DependsExpr, out: &mut Vec<String>) {
+    match expr {
+        DependsExpr::And(terms) => terms.iter().for_each(|t| select_leaves(vo, t, out)),
+        DependsExpr::Or(terms) => {
+            let satisfiable = terms.iter().find(|t| {
+                let mut leaves = Vec::new();
+                select_leaves(vo, t, &mut leaves);
+                leaves.iter().all(|leaf| is_known(vo, leaf))
+            });
+            if let Some(term) = satisfiable.or_else(|| terms.first()) {
+                select_leaves(vo, term, out);
+            }
+        }
+        DependsExpr::Version(v) => out.push(v.clone()),
+        DependsExpr::Extension(e) => out.push(e.clone()),
+        DependsExpr::Feature { .. } => {}
+    }
+}
+
+impl VulkanObject {
+    /// Resolve `requested` extension/version names into the full transitive
+    /// closure that must be enabled, topologically ordered.
+    pub fn resolve_extension_dependencies(&self, requested: &[&str]) -> Result<ResolvedSet, DepError> {
+        let mut order = Vec::new();
+        let mut visited = HashSet::new();
+        for &name in requested {
+            self.resolve_one(name, &mut visited, &mut order)?;
+        }
+
+        let platform_guards = order
+            .iter()
+            .filter_map(|name| {
+                let extension = self.extensions.get(name)?;
+                let platform = extension.platform.as_ref()?;
+                let guard = self.platforms.get(platform)?;
+                Some((name.clone(), guard.clone()))
+            })
+            .collect();
+
+        Ok(ResolvedSet {
+            order,
+            platform_guards,
+        })
+    }
+
+    fn resolve_one(
+        &self,
+        name: &str,
+        visited: &mut HashSet<String>,
+        order: &mut Vec<String>,
+    ) -> Result<(), DepError> {
+        if !visited.insert(name.to_string()) {
+            return Ok(());
+        }
+
+        if name == BASELINE_VERSION {
+            // Always available; nothing to enable for it.
+            return Ok(());
+        }
+
+        if let Some(extension) = self.extensions.get(name) {
+            let expr = DependsExpr::parse_opt(extension.depends.as_deref()).map_err(DepError::Parse)?;
+            let mut deps = Vec::new();
+            select_leaves(self, &expr, &mut deps);
+            for dep in deps {
+                self.resolve_one(&dep, visited, order)?;
+            }
+            order.push(name.to_string());
+        } else if self.versions.contains_key(name) {
+            order.push(name.to_string());
+        } else {
+            return Err(DepError::UnknownName(name.to_string()));
+        }
+        Ok(())
+    }
+}
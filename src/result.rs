@@ -0,0 +1,158 @@
+// Copyright 2023-2026 The Khronos Group Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Classifying `VkResult` enumerants and turning a command's
+//! `successCodes`/`errorCodes` into the lookup tables generated bindings use
+//! to build exhaustive `Result`-returning wrappers.
+
+use std::collections::HashMap;
+
+use crate::vulkan_object::VulkanObject;
+
+/// How a `VkResult` enumerant should be treated by a `Result`-returning
+/// wrapper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultClassification {
+    Success,
+    Error,
+    /// ex) `VK_INCOMPLETE`, `VK_SUBOPTIMAL_KHR` - technically a success code
+    /// but one callers almost always need to branch on.
+    Incomplete,
+}
+
+const INCOMPLETE_CODES: &[&str] = &["VK_INCOMPLETE", "VK_SUBOPTIMAL_KHR"];
+
+/// Turn `VK_ERROR_OUT_OF_HOST_MEMORY` into `"Out of host memory"`.
+fn describe(name: &str) -> String {
+    let trimmed = name
+        .trim_start_matches("VK_ERROR_")
+        .trim_start_matches("VK_");
+    let mut words = trimmed.split('_').filter(|w| !w.is_empty());
+    let mut description = String::new();
+    if let Some(first) = words.next() {
+        description.push_str(&first.to_ascii_uppercase_first());
+    }
+    for word in words {
+        description.push(' ');
+        description.push_str(&word.to_ascii_lowercase());
+    }
+    description
+}
+
+trait ToAsciiUppercaseFirst {
+    fn to_ascii_uppercase_first(&self) -> String;
+}
+
+impl ToAsciiUppercaseFirst for str {
+    fn to_ascii_uppercase_first(&self) -> String {
+        let mut chars = self.chars();
+        match chars.next() {
+            Some(first) => first.to_ascii_uppercase().to_string() + &chars.as_str().to_ascii_lowercase(),
+            None => String::new(),
+        }
+    }
+}
+
+impl VulkanObject {
+    /// Classify every `VkResult` enumerant as [`ResultClassification::Success`],
+    /// [`ResultClassification::Error`], or
+    /// [`ResultClassification::Incomplete`], along with a human-readable
+    /// description derived from its name.
+    pub fn result_classifications(&self) -> HashMap<String, (ResultClassification, String)> {
+        let Some(vk_result) = self.enums.get("VkResult") else {
+            return HashMap::new();
+        };
+        vk_result
+            .fields
+            .iter()
+            .map(|field| {
+                let classification = if INCOMPLETE_CODES.contains(&field.name.as_str()) {
+                    ResultClassification::Incomplete
+                } else if field.value < 0 {
+                    // `EnumField.negative` just means "this enum permits
+                    // negative values" and is set on every VkResult field;
+                    // the sign of the value itself is what distinguishes
+                    // errors from success codes.
+                    ResultClassification::Error
+                } else {
+                    ResultClassification::Success
+                };
+                (field.name.clone(), (classification, describe(&field.name)))
+            })
+            .collect()
+    }
+
+    /// The possible success and error enumerants for `command`, as declared
+    /// by its `successCodes`/`errorCodes`.
+    pub fn result_codes(&self, command: &str) -> (Vec<String>, Vec<String>) {
+        match self.commands.get(command) {
+            Some(command) => (command.success_codes.clone(), command.error_codes.clone()),
+            None => (Vec::new(), Vec::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_result_names() {
+        assert_eq!(describe("VK_ERROR_OUT_OF_HOST_MEMORY"), "Out of host memory");
+        assert_eq!(describe("VK_SUCCESS"), "Success");
+        assert_eq!(describe("VK_INCOMPLETE"), "Incomplete");
+    }
+
+    fn result_field(name: &str, value: i64) -> crate::vulkan_object::EnumField {
+        crate::vulkan_object::EnumField {
+            name: name.to_string(),
+            aliases: Vec::new(),
+            protect: None,
+            // `negative` just means "this enum permits negative values" and
+            // is true on every VkResult field, success codes included.
+            negative: true,
+            value,
+            value_str: value.to_string(),
+            extensions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn classifies_by_value_sign_not_by_negative_flag() {
+        use std::collections::HashMap;
+
+        let vk_result = crate::vulkan_object::Enum {
+            name: "VkResult".to_string(),
+            aliases: Vec::new(),
+            protect: None,
+            bit_width: 32,
+            returned_only: false,
+            fields: vec![
+                result_field("VK_SUCCESS", 0),
+                result_field("VK_NOT_READY", 1),
+                result_field("VK_INCOMPLETE", 5),
+                result_field("VK_ERROR_OUT_OF_HOST_MEMORY", -1),
+            ],
+            extensions: Vec::new(),
+            field_extensions: Vec::new(),
+            video_std_header: None,
+        };
+        let mut enums = HashMap::new();
+        enums.insert("VkResult".to_string(), vk_result);
+
+        let vo = crate::vulkan_object::VulkanObject {
+            enums,
+            ..crate::test_support::empty_vulkan_object()
+        };
+
+        let classifications = vo.result_classifications();
+        assert_eq!(classifications["VK_SUCCESS"].0, ResultClassification::Success);
+        assert_eq!(classifications["VK_NOT_READY"].0, ResultClassification::Success);
+        assert_eq!(classifications["VK_INCOMPLETE"].0, ResultClassification::Incomplete);
+        assert_eq!(
+            classifications["VK_ERROR_OUT_OF_HOST_MEMORY"].0,
+            ResultClassification::Error
+        );
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright 2023-2026 The Khronos Group Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Derived construction metadata for [`Struct`]: its `VkStructureType`
+//! enumerant, whether it can be chained into a `pNext`, and which members a
+//! generated builder can leave defaulted.
+
+use crate::vulkan_object::VulkanObject;
+
+/// What a binding generator needs to emit a correct `sType` and a sound
+/// `Default` impl for a struct.
+#[derive(Debug, Clone)]
+pub struct StructConstructionInfo<'a> {
+    /// The `VkStructureType` enumerant this struct's `sType` member must be
+    /// set to, if it has one.
+    pub s_type: Option<&'a str>,
+    /// Whether this struct can itself be chained into another struct's
+    /// `pNext` (i.e. it declares at least one `extends`).
+    pub pnext_eligible: bool,
+    /// Names of members marked `optional` in vk.xml, i.e. safe to leave at
+    /// their zero value in a `Default` impl.
+    pub defaultable_members: Vec<&'a str>,
+}
+
+impl VulkanObject {
+    /// The `VkStructureType` enumerant name associated with `name`'s
+    /// `sType` member, if it has one.
+    pub fn struct_type(&self, name: &str) -> Option<&str> {
+        self.structs.get(name)?.s_type.as_deref()
+    }
+
+    /// Construction metadata for `name`, combining its `sType`, pNext
+    /// chainability, and per-member defaultability.
+    pub fn construction_info(&self, name: &str) -> Option<StructConstructionInfo<'_>> {
+        let struct_ = self.structs.get(name)?;
+        let defaultable_members = struct_
+            .members
+            .iter()
+            .filter(|m| m.optional)
+            .map(|m| m.name.as_str())
+            .collect();
+        Some(StructConstructionInfo {
+            s_type: struct_.s_type.as_deref(),
+            pnext_eligible: !struct_.extends.is_empty(),
+            defaultable_members,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vulkan_object::{Member, Struct};
+    use std::collections::HashMap;
+
+    fn member(name: &str, optional: bool) -> Member {
+        Member {
+            name: name.to_string(),
+            type_: "uint32_t".to_string(),
+            full_type: "uint32_t".to_string(),
+            no_auto_validity: false,
+            limit_type: None,
+            const_: false,
+            length: None,
+            null_terminated: false,
+            pointer: false,
+            fixed_size_array: Vec::new(),
+            optional,
+            optional_pointer: false,
+            extern_sync: crate::vulkan_object::ExternSync::None,
+            c_declaration: format!("uint32_t {name}"),
+            bit_field_width: None,
+            selector: None,
+            selection: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn collects_defaultable_members() {
+        let mut structs = HashMap::new();
+        structs.insert(
+            "VkBufferCreateInfo".to_string(),
+            Struct {
+                name: "VkBufferCreateInfo".to_string(),
+                aliases: Vec::new(),
+                extensions: Vec::new(),
+                version: None,
+                protect: None,
+                members: vec![member("flags", true), member("size", false)],
+                union: false,
+                returned_only: false,
+                s_type: Some("VK_STRUCTURE_TYPE_BUFFER_CREATE_INFO".to_string()),
+                allow_duplicate: false,
+                extends: Vec::new(),
+                extended_by: Vec::new(),
+                video_std_header: None,
+            },
+        );
+
+        let vo = VulkanObject {
+            structs,
+            ..crate::test_support::empty_vulkan_object()
+        };
+
+        let info = vo.construction_info("VkBufferCreateInfo").unwrap();
+        assert_eq!(info.s_type, Some("VK_STRUCTURE_TYPE_BUFFER_CREATE_INFO"));
+        assert_eq!(info.defaultable_members, vec!["flags"]);
+        assert!(!info.pnext_eligible);
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright 2023-2026 The Khronos Group Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Expanding legacy synchronization stages/accesses into their
+//! synchronization2 equivalents via `SyncStage.equivalent` /
+//! `SyncAccess.equivalent`.
+
+use std::collections::HashSet;
+
+use crate::vulkan_object::{Flag, VulkanObject};
+
+impl VulkanObject {
+    /// Expand `names` (pipeline stage flag names, possibly legacy ones like
+    /// `VK_PIPELINE_STAGE_ALL_GRAPHICS_BIT`) into the fully expanded set of
+    /// `VkPipelineStageFlagBits2` flags, by unioning each input's
+    /// `SyncStage.equivalent.stages`. A `max` equivalent expands to every
+    /// stage flag known to the registry.
+    pub fn expand_stages(&self, names: &[&str]) -> Vec<Flag> {
+        let all_stages: Vec<&Flag> = self
+            .sync_stage
+            .iter()
+            .map(|s| &s.flag)
+            .collect();
+
+        let mut seen = HashSet::new();
+        let mut result = Vec::new();
+        for &name in names {
+            let Some(sync_stage) = self.sync_stage.iter().find(|s| s.flag.name == name) else {
+                continue;
+            };
+            if sync_stage.equivalent.max {
+                for &flag in &all_stages {
+                    if seen.insert(flag.value) {
+                        result.push(flag.clone());
+                    }
+                }
+                continue;
+            }
+            if let Some(stages) = &sync_stage.equivalent.stages {
+                for flag in stages {
+                    if seen.insert(flag.value) {
+                        result.push(flag.clone());
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Expand `names` (access flag names) into the fully expanded set of
+    /// `VkAccessFlagBits2` flags, analogous to [`Self::expand_stages`].
+    pub fn expand_accesses(&self, names: &[&str]) -> Vec<Flag> {
+        let all_accesses: Vec<&Flag> = self
+            .sync_access
+            .iter()
+            .map(|a| &a.flag)
+            .collect();
+
+        let mut seen = HashSet::new();
+        let mut result = Vec::new();
+        for &name in names {
+            let Some(sync_access) = self.sync_access.iter().find(|a| a.flag.name == name) else {
+                continue;
+            };
+            if sync_access.equivalent.max {
+                for &flag in &all_accesses {
+                    if seen.insert(flag.value) {
+                        result.push(flag.clone());
+                    }
+                }
+                continue;
+            }
+            if let Some(accesses) = &sync_access.equivalent.accesses {
+                for flag in accesses {
+                    if seen.insert(flag.value) {
+                        result.push(flag.clone());
+                    }
+                }
+            }
+        }
+        result
+    }
+}
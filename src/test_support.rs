@@ -0,0 +1,40 @@
+// Copyright 2023-2026 The Khronos Group Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Shared `#[cfg(test)]` fixtures so unit tests across the crate don't each
+//! repeat a full `VulkanObject { ... }` literal just to get an empty one.
+
+#![cfg(test)]
+
+use std::collections::HashMap;
+
+use crate::vulkan_object::VulkanObject;
+
+/// A `VulkanObject` with every collection empty, for tests that only care
+/// about one or two fields and fill in the rest via `..empty_vulkan_object()`.
+pub(crate) fn empty_vulkan_object() -> VulkanObject {
+    VulkanObject {
+        header_version: String::new(),
+        header_version_complete: String::new(),
+        extensions: HashMap::new(),
+        versions: HashMap::new(),
+        handles: HashMap::new(),
+        commands: HashMap::new(),
+        structs: HashMap::new(),
+        enums: HashMap::new(),
+        bitmasks: HashMap::new(),
+        flags: HashMap::new(),
+        constants: HashMap::new(),
+        formats: HashMap::new(),
+        sync_stage: Vec::new(),
+        sync_access: Vec::new(),
+        sync_pipeline: Vec::new(),
+        spirv: Vec::new(),
+        platforms: HashMap::new(),
+        vendor_tags: Vec::new(),
+        video_codecs: HashMap::new(),
+        video_std: None,
+        valid_usage: HashMap::new(),
+    }
+}
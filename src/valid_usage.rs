@@ -0,0 +1,102 @@
+// Copyright 2023-2026 The Khronos Group Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Loading the Vulkan registry's companion `validusage.json` and cross-linking
+//! its VUIDs onto the [`Command`]/[`Struct`] entries already present in a
+//! [`VulkanObject`].
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::vulkan_object::{ValidUsage, VulkanObject};
+
+/// Shape of `validusage.json`:
+/// `{ "validation": { "<entity name>": { "<context>": [ { ... } ] } } }`.
+///
+/// The context key groups statements by applicability -- `"core"` for
+/// unconditional ones, or a parenthesized `depends`-style expression such as
+/// `"(VK_VERSION_1_1,VK_KHR_maintenance1)"` -- rather than each statement
+/// carrying its own `apiVersion`/`extensions` fields.
+#[derive(Debug, Deserialize)]
+struct ValidUsageFile {
+    validation: HashMap<String, HashMap<String, Vec<RawValidUsage>>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawValidUsage {
+    vuid: String,
+    text: String,
+}
+
+/// Split a `validusage.json` context key into whether it's negated (a
+/// `"!(...)"` key, meaning the VUID applies when the named version/extensions
+/// are *absent*) and the version/extension tokens it gates on. `"core"` (and
+/// any other context with no parenthesized tokens) gates on nothing.
+fn parse_context(context: &str) -> (bool, Option<String>, Vec<String>) {
+    let trimmed = context.trim();
+    let negated = trimmed.starts_with('!');
+    let inner = trimmed
+        .trim_start_matches('!')
+        .trim_start_matches('(')
+        .trim_end_matches(')');
+    if inner.is_empty() || !trimmed.contains('(') {
+        return (false, None, Vec::new());
+    }
+    let mut api_version = None;
+    let mut extensions = Vec::new();
+    for token in inner.split([',', '+']) {
+        let token = token.trim();
+        if token.is_empty() {
+            continue;
+        }
+        if token.starts_with("VK_VERSION_") {
+            api_version.get_or_insert_with(|| token.to_string());
+        } else {
+            extensions.push(token.to_string());
+        }
+    }
+    (negated, api_version, extensions)
+}
+
+/// Parse a `validusage.json` document and attach each entity's Valid Usage
+/// statements onto `vo.valid_usage`, keyed by the command or struct name they
+/// apply to (matching vk.xml's naming, so they line up with
+/// `vo.commands`/`vo.structs`).
+pub fn attach_valid_usage(vo: &mut VulkanObject, validusage_json: &str) -> serde_json::Result<()> {
+    let file: ValidUsageFile = serde_json::from_str(validusage_json)?;
+    for (entity, contexts) in file.validation {
+        // Only keep statements for entities this registry actually knows
+        // about; validusage.json also covers constructs outside vk.xml
+        // (e.g. host synchronization boilerplate) that have no Command/Struct
+        // counterpart to attach to.
+        if !vo.commands.contains_key(&entity) && !vo.structs.contains_key(&entity) {
+            continue;
+        }
+        let mut converted = Vec::new();
+        for (context, statements) in contexts {
+            let (negated, api_version, extensions) = parse_context(&context);
+            converted.extend(statements.into_iter().map(|s| ValidUsage {
+                vuid: s.vuid,
+                text: s.text,
+                api_version: api_version.clone(),
+                extensions: extensions.clone(),
+                negated,
+            }));
+        }
+        vo.valid_usage.insert(entity, converted);
+    }
+    Ok(())
+}
+
+impl VulkanObject {
+    /// The Valid Usage statements that apply to `entity`, a command or
+    /// struct name. Empty if none were loaded or none apply.
+    pub fn valid_usage_for(&self, entity: &str) -> &[ValidUsage] {
+        self.valid_usage
+            .get(entity)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+}
@@ -13,7 +13,7 @@ use std::collections::HashMap;
 
 /// Each instance of FeatureRequirement is one part of the AND operation,
 /// unless the struct/field are the same, then the depends are AND together.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FeatureRequirement {
     #[serde(rename = "struct")]
     pub struct_: String,
@@ -79,7 +79,7 @@ pub struct Extension {
 
 /// `<feature>` which represents a version.
 /// This will NEVER be Version 1.0, since having 'no version' is same as being 1.0.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Version {
     /// ex) VK_VERSION_1_1
     pub name: String,
@@ -94,6 +94,44 @@ pub struct Version {
     pub feature_requirement: Vec<FeatureRequirement>,
 }
 
+impl Version {
+    /// Parse `name` (ex. `VK_VERSION_1_2`) into its `(major, minor)`
+    /// components.
+    pub fn components(&self) -> (u32, u32) {
+        let digits = self
+            .name
+            .strip_prefix("VK_VERSION_")
+            .unwrap_or(&self.name);
+        let (major, minor) = digits.split_once('_').unwrap_or((digits, "0"));
+        (
+            major.parse().unwrap_or(0),
+            minor.parse().unwrap_or(0),
+        )
+    }
+
+    /// Compute the packed `uint32_t` API version, matching
+    /// `VK_MAKE_API_VERSION(0, major, minor, 0)`.
+    pub fn encoded(&self) -> u32 {
+        let (major, minor) = self.components();
+        make_api_version(0, major, minor, 0)
+    }
+}
+
+/// Pack `(variant, major, minor, patch)` the same way `VK_MAKE_API_VERSION` does.
+pub fn make_api_version(variant: u32, major: u32, minor: u32, patch: u32) -> u32 {
+    (variant << 29) | (major << 22) | (minor << 12) | patch
+}
+
+/// Decode a packed `uint32_t` API version back into its
+/// `(variant, major, minor, patch)` components.
+pub fn decode_api_version(version: u32) -> (u32, u32, u32, u32) {
+    let variant = version >> 29;
+    let major = (version >> 22) & 0x7f;
+    let minor = (version >> 12) & 0x3ff;
+    let patch = version & 0xfff;
+    (variant, major, minor, patch)
+}
+
 /// `<deprecate>`
 /// For historical reasons, the XML tag is "deprecate" but we decided in the WG
 /// to not use that as the public facing name.
@@ -805,4 +843,47 @@ pub struct VulkanObject {
     /// Video Std header information from the video.xml
     #[serde(rename = "videoStd")]
     pub video_std: Option<VideoStd>,
+
+    /// Valid Usage statements from the companion `validusage.json`, keyed by
+    /// the command or struct name they apply to. Empty unless loaded separately via
+    /// [`crate::valid_usage::attach_valid_usage`], since this data doesn't
+    /// come from vk.json/vk.xml itself.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub valid_usage: HashMap<String, Vec<ValidUsage>>,
+}
+
+/// A single Valid Usage statement from `validusage.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidUsage {
+    /// ex) VUID-vkCmdDraw-commandBuffer-02707
+    pub vuid: String,
+    /// The English-language valid usage text.
+    pub text: String,
+    /// The core API version this VUID applies under, if version-gated.
+    #[serde(rename = "apiVersion")]
+    pub api_version: Option<String>,
+    /// The extensions this VUID applies under, if extension-gated.
+    pub extensions: Vec<String>,
+    /// If true, `api_version`/`extensions` name the condition under which
+    /// this VUID does *not* apply (a `"!(...)"` context key in
+    /// `validusage.json`) rather than the condition under which it does.
+    pub negated: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn version_encodes_like_make_api_version() {
+        let version = Version {
+            name: "VK_VERSION_1_2".to_string(),
+            name_string: "\"VK_VERSION_1_2\"".to_string(),
+            name_api: "VK_API_VERSION_1_2".to_string(),
+            feature_requirement: Vec::new(),
+        };
+        assert_eq!(version.components(), (1, 2));
+        assert_eq!(version.encoded(), make_api_version(0, 1, 2, 0));
+        assert_eq!(decode_api_version(version.encoded()), (0, 1, 2, 0));
+    }
 }